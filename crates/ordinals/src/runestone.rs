@@ -10,6 +10,10 @@ pub struct Runestone {
   pub etching: Option<Etching>,
   pub mint: Option<RuneId>,
   pub pointer: Option<u32>,
+  /// Split edicts divide runes in proportion to output value. Only recognized
+  /// when deciphering with weighted splits active.
+  #[serde(default)]
+  pub weighted: bool,
 }
 
 #[derive(Debug, PartialEq)]
@@ -22,7 +26,10 @@ impl Runestone {
   pub const MAGIC_NUMBER: opcodes::Opcode = opcodes::all::OP_PUSHNUM_13;
   pub const COMMIT_CONFIRMATIONS: u16 = 6;
 
-  pub fn decipher(transaction: &Transaction) -> Option<Artifact> {
+  /// Decipher the runestone in `transaction`. Before weighted splits activate,
+  /// the weighted flag is unrecognized, and runestones setting it are
+  /// cenotaphs.
+  pub fn decipher(transaction: &Transaction, weighted_splits: bool) -> Option<Artifact> {
     let payload = match Runestone::payload(transaction) {
       Some(Payload::Valid(payload)) => payload,
       Some(Payload::Invalid(flaw)) => {
@@ -86,6 +93,8 @@ impl Runestone {
       turbo: Flag::Turbo.take(&mut flags),
     });
 
+    let weighted = weighted_splits && Flag::Weighted.take(&mut flags);
+
     let mint = Tag::Mint.take(&mut fields, |[block, tx]| {
       RuneId::new(block.try_into().ok()?, tx.try_into().ok()?)
     });
@@ -123,14 +132,16 @@ impl Runestone {
       etching,
       mint,
       pointer,
+      weighted,
     }))
   }
 
   pub fn encipher(&self) -> ScriptBuf {
     let mut payload = Vec::new();
 
+    let mut flags = 0;
+
     if let Some(etching) = self.etching {
-      let mut flags = 0;
       Flag::Etching.set(&mut flags);
 
       if etching.terms.is_some() {
@@ -140,9 +151,17 @@ impl Runestone {
       if etching.turbo {
         Flag::Turbo.set(&mut flags);
       }
+    }
 
+    if self.weighted {
+      Flag::Weighted.set(&mut flags);
+    }
+
+    if flags != 0 {
       Tag::Flags.encode([flags], &mut payload);
+    }
 
+    if let Some(etching) = self.etching {
       Tag::Rune.encode_option(etching.rune.map(|rune| rune.0), &mut payload);
       Tag::Divisibility.encode_option(etching.divisibility, &mut payload);
       Tag::Spacers.encode_option(etching.spacers, &mut payload);
@@ -263,23 +282,30 @@ mod tests {
   }
 
   fn decipher(integers: &[u128]) -> Artifact {
+    decipher_with_weighted_splits(integers, false)
+  }
+
+  fn decipher_with_weighted_splits(integers: &[u128], weighted_splits: bool) -> Artifact {
     let payload = payload(integers);
 
     let payload: &PushBytes = payload.as_slice().try_into().unwrap();
 
-    Runestone::decipher(&Transaction {
-      input: Vec::new(),
-      output: vec![TxOut {
-        script_pubkey: script::Builder::new()
-          .push_opcode(opcodes::all::OP_RETURN)
-          .push_opcode(Runestone::MAGIC_NUMBER)
-          .push_slice(payload)
-          .into_script(),
-        value: Amount::from_sat(0),
-      }],
-      lock_time: LockTime::ZERO,
-      version: Version(2),
-    })
+    Runestone::decipher(
+      &Transaction {
+        input: Vec::new(),
+        output: vec![TxOut {
+          script_pubkey: script::Builder::new()
+            .push_opcode(opcodes::all::OP_RETURN)
+            .push_opcode(Runestone::MAGIC_NUMBER)
+            .push_slice(payload)
+            .into_script(),
+          value: Amount::from_sat(0),
+        }],
+        lock_time: LockTime::ZERO,
+        version: Version(2),
+      },
+      weighted_splits,
+    )
     .unwrap()
   }
 
@@ -296,15 +322,18 @@ mod tests {
   #[test]
   fn decipher_returns_none_if_first_opcode_is_malformed() {
     assert_eq!(
-      Runestone::decipher(&Transaction {
-        input: Vec::new(),
-        output: vec![TxOut {
-          script_pubkey: ScriptBuf::from_bytes(vec![opcodes::all::OP_PUSHBYTES_4.to_u8()]),
-          value: Amount::from_sat(0),
-        }],
-        lock_time: LockTime::ZERO,
-        version: Version(2),
-      }),
+      Runestone::decipher(
+        &Transaction {
+          input: Vec::new(),
+          output: vec![TxOut {
+            script_pubkey: ScriptBuf::from_bytes(vec![opcodes::all::OP_PUSHBYTES_4.to_u8()]),
+            value: Amount::from_sat(0),
+          }],
+          lock_time: LockTime::ZERO,
+          version: Version(2),
+        },
+        false
+      ),
       None,
     );
   }
@@ -312,12 +341,15 @@ mod tests {
   #[test]
   fn deciphering_transaction_with_no_outputs_returns_none() {
     assert_eq!(
-      Runestone::decipher(&Transaction {
-        input: Vec::new(),
-        output: Vec::new(),
-        lock_time: LockTime::ZERO,
-        version: Version(2),
-      }),
+      Runestone::decipher(
+        &Transaction {
+          input: Vec::new(),
+          output: Vec::new(),
+          lock_time: LockTime::ZERO,
+          version: Version(2),
+        },
+        false
+      ),
       None,
     );
   }
@@ -325,15 +357,18 @@ mod tests {
   #[test]
   fn deciphering_transaction_with_non_op_return_output_returns_none() {
     assert_eq!(
-      Runestone::decipher(&Transaction {
-        input: Vec::new(),
-        output: vec![TxOut {
-          script_pubkey: script::Builder::new().push_slice([]).into_script(),
-          value: Amount::from_sat(0),
-        }],
-        lock_time: LockTime::ZERO,
-        version: Version(2),
-      }),
+      Runestone::decipher(
+        &Transaction {
+          input: Vec::new(),
+          output: vec![TxOut {
+            script_pubkey: script::Builder::new().push_slice([]).into_script(),
+            value: Amount::from_sat(0),
+          }],
+          lock_time: LockTime::ZERO,
+          version: Version(2),
+        },
+        false
+      ),
       None,
     );
   }
@@ -341,17 +376,20 @@ mod tests {
   #[test]
   fn deciphering_transaction_with_bare_op_return_returns_none() {
     assert_eq!(
-      Runestone::decipher(&Transaction {
-        input: Vec::new(),
-        output: vec![TxOut {
-          script_pubkey: script::Builder::new()
-            .push_opcode(opcodes::all::OP_RETURN)
-            .into_script(),
-          value: Amount::from_sat(0),
-        }],
-        lock_time: LockTime::ZERO,
-        version: Version(2),
-      }),
+      Runestone::decipher(
+        &Transaction {
+          input: Vec::new(),
+          output: vec![TxOut {
+            script_pubkey: script::Builder::new()
+              .push_opcode(opcodes::all::OP_RETURN)
+              .into_script(),
+            value: Amount::from_sat(0),
+          }],
+          lock_time: LockTime::ZERO,
+          version: Version(2),
+        },
+        false
+      ),
       None,
     );
   }
@@ -359,18 +397,21 @@ mod tests {
   #[test]
   fn deciphering_transaction_with_non_matching_op_return_returns_none() {
     assert_eq!(
-      Runestone::decipher(&Transaction {
-        input: Vec::new(),
-        output: vec![TxOut {
-          script_pubkey: script::Builder::new()
-            .push_opcode(opcodes::all::OP_RETURN)
-            .push_slice(b"FOOO")
-            .into_script(),
-          value: Amount::from_sat(0),
-        }],
-        lock_time: LockTime::ZERO,
-        version: Version(2),
-      }),
+      Runestone::decipher(
+        &Transaction {
+          input: Vec::new(),
+          output: vec![TxOut {
+            script_pubkey: script::Builder::new()
+              .push_opcode(opcodes::all::OP_RETURN)
+              .push_slice(b"FOOO")
+              .into_script(),
+            value: Amount::from_sat(0),
+          }],
+          lock_time: LockTime::ZERO,
+          version: Version(2),
+        },
+        false
+      ),
       None,
     );
   }
@@ -401,55 +442,61 @@ mod tests {
 
   #[test]
   fn deciphering_runestone_with_truncated_varint_succeeds() {
-    Runestone::decipher(&Transaction {
-      input: Vec::new(),
-      output: vec![TxOut {
-        script_pubkey: script::Builder::new()
-          .push_opcode(opcodes::all::OP_RETURN)
-          .push_opcode(Runestone::MAGIC_NUMBER)
-          .push_slice([128])
-          .into_script(),
-        value: Amount::from_sat(0),
-      }],
-      lock_time: LockTime::ZERO,
-      version: Version(2),
-    })
+    Runestone::decipher(
+      &Transaction {
+        input: Vec::new(),
+        output: vec![TxOut {
+          script_pubkey: script::Builder::new()
+            .push_opcode(opcodes::all::OP_RETURN)
+            .push_opcode(Runestone::MAGIC_NUMBER)
+            .push_slice([128])
+            .into_script(),
+          value: Amount::from_sat(0),
+        }],
+        lock_time: LockTime::ZERO,
+        version: Version(2),
+      },
+      false,
+    )
     .unwrap();
   }
 
   #[test]
   fn outputs_with_non_pushdata_opcodes_are_cenotaph() {
     assert_eq!(
-      Runestone::decipher(&Transaction {
-        input: Vec::new(),
-        output: vec![
-          TxOut {
-            script_pubkey: script::Builder::new()
-              .push_opcode(opcodes::all::OP_RETURN)
-              .push_opcode(Runestone::MAGIC_NUMBER)
-              .push_opcode(opcodes::all::OP_VERIFY)
-              .push_slice([0])
-              .push_slice::<&PushBytes>(varint::encode(1).as_slice().try_into().unwrap())
-              .push_slice::<&PushBytes>(varint::encode(1).as_slice().try_into().unwrap())
-              .push_slice([2, 0])
-              .into_script(),
-            value: Amount::from_sat(0),
-          },
-          TxOut {
-            script_pubkey: script::Builder::new()
-              .push_opcode(opcodes::all::OP_RETURN)
-              .push_opcode(Runestone::MAGIC_NUMBER)
-              .push_slice([0])
-              .push_slice::<&PushBytes>(varint::encode(1).as_slice().try_into().unwrap())
-              .push_slice::<&PushBytes>(varint::encode(2).as_slice().try_into().unwrap())
-              .push_slice([3, 0])
-              .into_script(),
-            value: Amount::from_sat(0),
-          },
-        ],
-        lock_time: LockTime::ZERO,
-        version: Version(2),
-      })
+      Runestone::decipher(
+        &Transaction {
+          input: Vec::new(),
+          output: vec![
+            TxOut {
+              script_pubkey: script::Builder::new()
+                .push_opcode(opcodes::all::OP_RETURN)
+                .push_opcode(Runestone::MAGIC_NUMBER)
+                .push_opcode(opcodes::all::OP_VERIFY)
+                .push_slice([0])
+                .push_slice::<&PushBytes>(varint::encode(1).as_slice().try_into().unwrap())
+                .push_slice::<&PushBytes>(varint::encode(1).as_slice().try_into().unwrap())
+                .push_slice([2, 0])
+                .into_script(),
+              value: Amount::from_sat(0),
+            },
+            TxOut {
+              script_pubkey: script::Builder::new()
+                .push_opcode(opcodes::all::OP_RETURN)
+                .push_opcode(Runestone::MAGIC_NUMBER)
+                .push_slice([0])
+                .push_slice::<&PushBytes>(varint::encode(1).as_slice().try_into().unwrap())
+                .push_slice::<&PushBytes>(varint::encode(2).as_slice().try_into().unwrap())
+                .push_slice([3, 0])
+                .into_script(),
+              value: Amount::from_sat(0),
+            },
+          ],
+          lock_time: LockTime::ZERO,
+          version: Version(2),
+        },
+        false
+      )
       .unwrap(),
       Artifact::Cenotaph(Cenotaph {
         flaw: Some(Flaw::Opcode),
@@ -461,19 +508,22 @@ mod tests {
   #[test]
   fn pushnum_opcodes_in_runestone_produce_cenotaph() {
     assert_eq!(
-      Runestone::decipher(&Transaction {
-        input: Vec::new(),
-        output: vec![TxOut {
-          script_pubkey: script::Builder::new()
-            .push_opcode(opcodes::all::OP_RETURN)
-            .push_opcode(Runestone::MAGIC_NUMBER)
-            .push_opcode(opcodes::all::OP_PUSHNUM_1)
-            .into_script(),
-          value: Amount::from_sat(0),
-        },],
-        lock_time: LockTime::ZERO,
-        version: Version(2),
-      })
+      Runestone::decipher(
+        &Transaction {
+          input: Vec::new(),
+          output: vec![TxOut {
+            script_pubkey: script::Builder::new()
+              .push_opcode(opcodes::all::OP_RETURN)
+              .push_opcode(Runestone::MAGIC_NUMBER)
+              .push_opcode(opcodes::all::OP_PUSHNUM_1)
+              .into_script(),
+            value: Amount::from_sat(0),
+          },],
+          lock_time: LockTime::ZERO,
+          version: Version(2),
+        },
+        false
+      )
       .unwrap(),
       Artifact::Cenotaph(Cenotaph {
         flaw: Some(Flaw::Opcode),
@@ -485,18 +535,21 @@ mod tests {
   #[test]
   fn deciphering_empty_runestone_is_successful() {
     assert_eq!(
-      Runestone::decipher(&Transaction {
-        input: Vec::new(),
-        output: vec![TxOut {
-          script_pubkey: script::Builder::new()
-            .push_opcode(opcodes::all::OP_RETURN)
-            .push_opcode(Runestone::MAGIC_NUMBER)
-            .into_script(),
-          value: Amount::from_sat(0),
-        }],
-        lock_time: LockTime::ZERO,
-        version: Version(2),
-      })
+      Runestone::decipher(
+        &Transaction {
+          input: Vec::new(),
+          output: vec![TxOut {
+            script_pubkey: script::Builder::new()
+              .push_opcode(opcodes::all::OP_RETURN)
+              .push_opcode(Runestone::MAGIC_NUMBER)
+              .into_script(),
+            value: Amount::from_sat(0),
+          }],
+          lock_time: LockTime::ZERO,
+          version: Version(2),
+        },
+        false
+      )
       .unwrap(),
       Artifact::Runestone(Runestone::default()),
     );
@@ -516,25 +569,28 @@ mod tests {
     ];
 
     assert_eq!(
-      Runestone::decipher(&Transaction {
-        input: Vec::new(),
-        output: vec![
-          TxOut {
-            script_pubkey: ScriptBuf::from_bytes(script_pubkey),
-            value: Amount::from_sat(0),
-          },
-          TxOut {
-            script_pubkey: script::Builder::new()
-              .push_opcode(opcodes::all::OP_RETURN)
-              .push_opcode(Runestone::MAGIC_NUMBER)
-              .push_slice(payload)
-              .into_script(),
-            value: Amount::from_sat(0),
-          },
-        ],
-        lock_time: LockTime::ZERO,
-        version: Version(2),
-      })
+      Runestone::decipher(
+        &Transaction {
+          input: Vec::new(),
+          output: vec![
+            TxOut {
+              script_pubkey: ScriptBuf::from_bytes(script_pubkey),
+              value: Amount::from_sat(0),
+            },
+            TxOut {
+              script_pubkey: script::Builder::new()
+                .push_opcode(opcodes::all::OP_RETURN)
+                .push_opcode(Runestone::MAGIC_NUMBER)
+                .push_slice(payload)
+                .into_script(),
+              value: Amount::from_sat(0),
+            },
+          ],
+          lock_time: LockTime::ZERO,
+          version: Version(2),
+        },
+        false
+      )
       .unwrap(),
       Artifact::Runestone(Runestone {
         mint: Some(RuneId::new(1, 1).unwrap()),
@@ -611,6 +667,84 @@ mod tests {
     );
   }
 
+  #[test]
+  fn weighted_flag_without_etching_flag_is_valid() {
+    assert_eq!(
+      decipher_with_weighted_splits(
+        &[
+          Tag::Flags.into(),
+          Flag::Weighted.mask(),
+          Tag::Body.into(),
+          1,
+          1,
+          0,
+          1,
+        ],
+        true
+      ),
+      Artifact::Runestone(Runestone {
+        edicts: vec![Edict {
+          id: rune_id(1),
+          amount: 0,
+          output: 1,
+        }],
+        weighted: true,
+        ..default()
+      }),
+    );
+  }
+
+  #[test]
+  fn weighted_flag_with_etching_flag_is_valid() {
+    assert_eq!(
+      decipher_with_weighted_splits(
+        &[
+          Tag::Flags.into(),
+          Flag::Etching.mask() | Flag::Weighted.mask(),
+          Tag::Rune.into(),
+          4,
+        ],
+        true
+      ),
+      Artifact::Runestone(Runestone {
+        etching: Some(Etching {
+          rune: Some(Rune(4)),
+          ..default()
+        }),
+        weighted: true,
+        ..default()
+      }),
+    );
+  }
+
+  #[test]
+  fn weighted_flag_before_weighted_splits_produces_cenotaph() {
+    assert_eq!(
+      decipher(&[
+        Tag::Flags.into(),
+        Flag::Etching.mask() | Flag::Weighted.mask(),
+        Tag::Rune.into(),
+        4,
+      ]),
+      Artifact::Cenotaph(Cenotaph {
+        etching: Some(Rune(4)),
+        flaw: Some(Flaw::UnrecognizedFlag),
+        ..default()
+      }),
+    );
+  }
+
+  #[test]
+  fn weighted_defaults_to_false_when_deserializing() {
+    assert_eq!(
+      serde_json::from_str::<Runestone>(
+        r#"{"edicts":[],"etching":null,"mint":null,"pointer":null}"#
+      )
+      .unwrap(),
+      Runestone::default(),
+    );
+  }
+
   #[test]
   fn terms_flag_without_etching_flag_produces_cenotaph() {
     assert_eq!(
@@ -752,19 +886,22 @@ mod tests {
   #[test]
   fn invalid_varint_produces_cenotaph() {
     assert_eq!(
-      Runestone::decipher(&Transaction {
-        input: Vec::new(),
-        output: vec![TxOut {
-          script_pubkey: script::Builder::new()
-            .push_opcode(opcodes::all::OP_RETURN)
-            .push_opcode(Runestone::MAGIC_NUMBER)
-            .push_slice([128])
-            .into_script(),
-          value: Amount::from_sat(0),
-        }],
-        lock_time: LockTime::ZERO,
-        version: Version(2),
-      })
+      Runestone::decipher(
+        &Transaction {
+          input: Vec::new(),
+          output: vec![TxOut {
+            script_pubkey: script::Builder::new()
+              .push_opcode(opcodes::all::OP_RETURN)
+              .push_opcode(Runestone::MAGIC_NUMBER)
+              .push_slice([128])
+              .into_script(),
+            value: Amount::from_sat(0),
+          }],
+          lock_time: LockTime::ZERO,
+          version: Version(2),
+        },
+        false
+      )
       .unwrap(),
       Artifact::Cenotaph(Cenotaph {
         flaw: Some(Flaw::Varint),
@@ -1257,47 +1394,50 @@ mod tests {
   #[test]
   fn payload_pushes_are_concatenated() {
     assert_eq!(
-      Runestone::decipher(&Transaction {
-        input: Vec::new(),
-        output: vec![TxOut {
-          script_pubkey: script::Builder::new()
-            .push_opcode(opcodes::all::OP_RETURN)
-            .push_opcode(Runestone::MAGIC_NUMBER)
-            .push_slice::<&PushBytes>(
-              varint::encode(Tag::Flags.into())
-                .as_slice()
-                .try_into()
-                .unwrap()
-            )
-            .push_slice::<&PushBytes>(
-              varint::encode(Flag::Etching.mask())
-                .as_slice()
-                .try_into()
-                .unwrap()
-            )
-            .push_slice::<&PushBytes>(
-              varint::encode(Tag::Divisibility.into())
-                .as_slice()
-                .try_into()
-                .unwrap()
-            )
-            .push_slice::<&PushBytes>(varint::encode(5).as_slice().try_into().unwrap())
-            .push_slice::<&PushBytes>(
-              varint::encode(Tag::Body.into())
-                .as_slice()
-                .try_into()
-                .unwrap()
-            )
-            .push_slice::<&PushBytes>(varint::encode(1).as_slice().try_into().unwrap())
-            .push_slice::<&PushBytes>(varint::encode(1).as_slice().try_into().unwrap())
-            .push_slice::<&PushBytes>(varint::encode(2).as_slice().try_into().unwrap())
-            .push_slice::<&PushBytes>(varint::encode(0).as_slice().try_into().unwrap())
-            .into_script(),
-          value: Amount::from_sat(0),
-        }],
-        lock_time: LockTime::ZERO,
-        version: Version(2),
-      })
+      Runestone::decipher(
+        &Transaction {
+          input: Vec::new(),
+          output: vec![TxOut {
+            script_pubkey: script::Builder::new()
+              .push_opcode(opcodes::all::OP_RETURN)
+              .push_opcode(Runestone::MAGIC_NUMBER)
+              .push_slice::<&PushBytes>(
+                varint::encode(Tag::Flags.into())
+                  .as_slice()
+                  .try_into()
+                  .unwrap()
+              )
+              .push_slice::<&PushBytes>(
+                varint::encode(Flag::Etching.mask())
+                  .as_slice()
+                  .try_into()
+                  .unwrap()
+              )
+              .push_slice::<&PushBytes>(
+                varint::encode(Tag::Divisibility.into())
+                  .as_slice()
+                  .try_into()
+                  .unwrap()
+              )
+              .push_slice::<&PushBytes>(varint::encode(5).as_slice().try_into().unwrap())
+              .push_slice::<&PushBytes>(
+                varint::encode(Tag::Body.into())
+                  .as_slice()
+                  .try_into()
+                  .unwrap()
+              )
+              .push_slice::<&PushBytes>(varint::encode(1).as_slice().try_into().unwrap())
+              .push_slice::<&PushBytes>(varint::encode(1).as_slice().try_into().unwrap())
+              .push_slice::<&PushBytes>(varint::encode(2).as_slice().try_into().unwrap())
+              .push_slice::<&PushBytes>(varint::encode(0).as_slice().try_into().unwrap())
+              .into_script(),
+            value: Amount::from_sat(0),
+          }],
+          lock_time: LockTime::ZERO,
+          version: Version(2),
+        },
+        false
+      )
       .unwrap(),
       Artifact::Runestone(Runestone {
         edicts: vec![Edict {
@@ -1321,25 +1461,28 @@ mod tests {
     let payload: &PushBytes = payload.as_slice().try_into().unwrap();
 
     assert_eq!(
-      Runestone::decipher(&Transaction {
-        input: Vec::new(),
-        output: vec![
-          TxOut {
-            script_pubkey: ScriptBuf::new(),
-            value: Amount::from_sat(0),
-          },
-          TxOut {
-            script_pubkey: script::Builder::new()
-              .push_opcode(opcodes::all::OP_RETURN)
-              .push_opcode(Runestone::MAGIC_NUMBER)
-              .push_slice(payload)
-              .into_script(),
-            value: Amount::from_sat(0),
-          }
-        ],
-        lock_time: LockTime::ZERO,
-        version: Version(2),
-      })
+      Runestone::decipher(
+        &Transaction {
+          input: Vec::new(),
+          output: vec![
+            TxOut {
+              script_pubkey: ScriptBuf::new(),
+              value: Amount::from_sat(0),
+            },
+            TxOut {
+              script_pubkey: script::Builder::new()
+                .push_opcode(opcodes::all::OP_RETURN)
+                .push_opcode(Runestone::MAGIC_NUMBER)
+                .push_slice(payload)
+                .into_script(),
+              value: Amount::from_sat(0),
+            }
+          ],
+          lock_time: LockTime::ZERO,
+          version: Version(2),
+        },
+        false
+      )
       .unwrap(),
       Artifact::Runestone(Runestone {
         edicts: vec![Edict {
@@ -1359,28 +1502,31 @@ mod tests {
     let payload: &PushBytes = payload.as_slice().try_into().unwrap();
 
     assert_eq!(
-      Runestone::decipher(&Transaction {
-        input: Vec::new(),
-        output: vec![
-          TxOut {
-            script_pubkey: script::Builder::new()
-              .push_opcode(opcodes::all::OP_RETURN)
-              .push_slice(b"FOO")
-              .into_script(),
-            value: Amount::from_sat(0),
-          },
-          TxOut {
-            script_pubkey: script::Builder::new()
-              .push_opcode(opcodes::all::OP_RETURN)
-              .push_opcode(Runestone::MAGIC_NUMBER)
-              .push_slice(payload)
-              .into_script(),
-            value: Amount::from_sat(0),
-          }
-        ],
-        lock_time: LockTime::ZERO,
-        version: Version(2),
-      })
+      Runestone::decipher(
+        &Transaction {
+          input: Vec::new(),
+          output: vec![
+            TxOut {
+              script_pubkey: script::Builder::new()
+                .push_opcode(opcodes::all::OP_RETURN)
+                .push_slice(b"FOO")
+                .into_script(),
+              value: Amount::from_sat(0),
+            },
+            TxOut {
+              script_pubkey: script::Builder::new()
+                .push_opcode(opcodes::all::OP_RETURN)
+                .push_opcode(Runestone::MAGIC_NUMBER)
+                .push_slice(payload)
+                .into_script(),
+              value: Amount::from_sat(0),
+            }
+          ],
+          lock_time: LockTime::ZERO,
+          version: Version(2),
+        },
+        false
+      )
       .unwrap(),
       Artifact::Runestone(Runestone {
         edicts: vec![Edict {
@@ -1679,7 +1825,7 @@ mod tests {
       };
 
       assert_eq!(
-        Runestone::decipher(&transaction).unwrap(),
+        Runestone::decipher(&transaction, true).unwrap(),
         Artifact::Runestone(runestone),
       );
     }
@@ -1791,6 +1937,29 @@ mod tests {
       },
       &[Tag::Flags.into(), Flag::Etching.mask()],
     );
+
+    case(
+      Runestone {
+        weighted: true,
+        ..default()
+      },
+      &[Tag::Flags.into(), Flag::Weighted.mask()],
+    );
+
+    case(
+      Runestone {
+        etching: Some(Etching {
+          turbo: true,
+          ..default()
+        }),
+        weighted: true,
+        ..default()
+      },
+      &[
+        Tag::Flags.into(),
+        Flag::Etching.mask() | Flag::Turbo.mask() | Flag::Weighted.mask(),
+      ],
+    );
   }
 
   #[test]
@@ -2028,50 +2197,56 @@ mod tests {
   #[test]
   fn invalid_scripts_in_op_returns_without_magic_number_are_ignored() {
     assert_eq!(
-      Runestone::decipher(&Transaction {
-        version: Version(2),
-        lock_time: LockTime::ZERO,
-        input: vec![TxIn {
-          previous_output: OutPoint::null(),
-          script_sig: ScriptBuf::new(),
-          sequence: Sequence::ENABLE_RBF_NO_LOCKTIME,
-          witness: Witness::new(),
-        }],
-        output: vec![TxOut {
-          script_pubkey: ScriptBuf::from(vec![
-            opcodes::all::OP_RETURN.to_u8(),
-            opcodes::all::OP_PUSHBYTES_4.to_u8(),
-          ]),
-          value: Amount::from_sat(0),
-        }],
-      }),
-      None
-    );
-
-    assert_eq!(
-      Runestone::decipher(&Transaction {
-        version: Version(2),
-        lock_time: LockTime::ZERO,
-        input: vec![TxIn {
-          previous_output: OutPoint::null(),
-          script_sig: ScriptBuf::new(),
-          sequence: Sequence::ENABLE_RBF_NO_LOCKTIME,
-          witness: Witness::new(),
-        }],
-        output: vec![
-          TxOut {
+      Runestone::decipher(
+        &Transaction {
+          version: Version(2),
+          lock_time: LockTime::ZERO,
+          input: vec![TxIn {
+            previous_output: OutPoint::null(),
+            script_sig: ScriptBuf::new(),
+            sequence: Sequence::ENABLE_RBF_NO_LOCKTIME,
+            witness: Witness::new(),
+          }],
+          output: vec![TxOut {
             script_pubkey: ScriptBuf::from(vec![
               opcodes::all::OP_RETURN.to_u8(),
               opcodes::all::OP_PUSHBYTES_4.to_u8(),
             ]),
             value: Amount::from_sat(0),
-          },
-          TxOut {
-            script_pubkey: Runestone::default().encipher(),
-            value: Amount::from_sat(0),
-          }
-        ],
-      })
+          }],
+        },
+        false
+      ),
+      None
+    );
+
+    assert_eq!(
+      Runestone::decipher(
+        &Transaction {
+          version: Version(2),
+          lock_time: LockTime::ZERO,
+          input: vec![TxIn {
+            previous_output: OutPoint::null(),
+            script_sig: ScriptBuf::new(),
+            sequence: Sequence::ENABLE_RBF_NO_LOCKTIME,
+            witness: Witness::new(),
+          }],
+          output: vec![
+            TxOut {
+              script_pubkey: ScriptBuf::from(vec![
+                opcodes::all::OP_RETURN.to_u8(),
+                opcodes::all::OP_PUSHBYTES_4.to_u8(),
+              ]),
+              value: Amount::from_sat(0),
+            },
+            TxOut {
+              script_pubkey: Runestone::default().encipher(),
+              value: Amount::from_sat(0),
+            }
+          ],
+        },
+        false
+      )
       .unwrap(),
       Artifact::Runestone(Runestone::default()),
     );
@@ -2080,24 +2255,27 @@ mod tests {
   #[test]
   fn invalid_scripts_in_op_returns_with_magic_number_produce_cenotaph() {
     assert_eq!(
-      Runestone::decipher(&Transaction {
-        version: Version(2),
-        lock_time: LockTime::ZERO,
-        input: vec![TxIn {
-          previous_output: OutPoint::null(),
-          script_sig: ScriptBuf::new(),
-          sequence: Sequence::ENABLE_RBF_NO_LOCKTIME,
-          witness: Witness::new(),
-        }],
-        output: vec![TxOut {
-          script_pubkey: ScriptBuf::from(vec![
-            opcodes::all::OP_RETURN.to_u8(),
-            Runestone::MAGIC_NUMBER.to_u8(),
-            opcodes::all::OP_PUSHBYTES_4.to_u8(),
-          ]),
-          value: Amount::from_sat(0),
-        }],
-      })
+      Runestone::decipher(
+        &Transaction {
+          version: Version(2),
+          lock_time: LockTime::ZERO,
+          input: vec![TxIn {
+            previous_output: OutPoint::null(),
+            script_sig: ScriptBuf::new(),
+            sequence: Sequence::ENABLE_RBF_NO_LOCKTIME,
+            witness: Witness::new(),
+          }],
+          output: vec![TxOut {
+            script_pubkey: ScriptBuf::from(vec![
+              opcodes::all::OP_RETURN.to_u8(),
+              Runestone::MAGIC_NUMBER.to_u8(),
+              opcodes::all::OP_PUSHBYTES_4.to_u8(),
+            ]),
+            value: Amount::from_sat(0),
+          }],
+        },
+        false
+      )
       .unwrap(),
       Artifact::Cenotaph(Cenotaph {
         flaw: Some(Flaw::InvalidScript),
@@ -2143,15 +2321,18 @@ mod tests {
       }
 
       assert_eq!(
-        Runestone::decipher(&Transaction {
-          version: Version(2),
-          lock_time: LockTime::ZERO,
-          input: default(),
-          output: vec![TxOut {
-            script_pubkey: script_pubkey.into(),
-            value: Amount::from_sat(0),
-          },],
-        })
+        Runestone::decipher(
+          &Transaction {
+            version: Version(2),
+            lock_time: LockTime::ZERO,
+            input: default(),
+            output: vec![TxOut {
+              script_pubkey: script_pubkey.into(),
+              value: Amount::from_sat(0),
+            },],
+          },
+          false
+        )
         .unwrap(),
         Artifact::Runestone(Runestone::default()),
       );
@@ -2162,20 +2343,23 @@ mod tests {
   fn all_non_pushdata_opcodes_are_invalid() {
     for i in 79..=u8::MAX {
       assert_eq!(
-        Runestone::decipher(&Transaction {
-          version: Version(2),
-          lock_time: LockTime::ZERO,
-          input: default(),
-          output: vec![TxOut {
-            script_pubkey: vec![
-              opcodes::all::OP_RETURN.to_u8(),
-              Runestone::MAGIC_NUMBER.to_u8(),
-              i
-            ]
-            .into(),
-            value: Amount::from_sat(0),
-          },],
-        })
+        Runestone::decipher(
+          &Transaction {
+            version: Version(2),
+            lock_time: LockTime::ZERO,
+            input: default(),
+            output: vec![TxOut {
+              script_pubkey: vec![
+                opcodes::all::OP_RETURN.to_u8(),
+                Runestone::MAGIC_NUMBER.to_u8(),
+                i
+              ]
+              .into(),
+              value: Amount::from_sat(0),
+            },],
+          },
+          false
+        )
         .unwrap(),
         Artifact::Cenotaph(Cenotaph {
           flaw: Some(Flaw::Opcode),
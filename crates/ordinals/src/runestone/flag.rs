@@ -2,6 +2,7 @@ pub(super) enum Flag {
   Etching = 0,
   Terms = 1,
   Turbo = 2,
+  Weighted = 3,
   #[allow(unused)]
   Cenotaph = 127,
 }
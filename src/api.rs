@@ -89,6 +89,21 @@ pub struct ChildInscriptions {
   pub page: usize,
 }
 
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+pub struct CommitmentRequest {
+  pub transaction: String,
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+pub struct CommitmentStatus {
+  pub commit_height: Option<u32>,
+  pub confirmations: Option<u32>,
+  pub input: Option<OutPoint>,
+  pub required_confirmations: u32,
+  pub taproot: bool,
+  pub valid: bool,
+}
+
 #[derive(Debug, PartialEq, Serialize, Deserialize)]
 pub struct ParentInscriptions {
   pub parents: Vec<RelativeInscriptionRecursive>,
@@ -213,6 +228,14 @@ impl Output {
   }
 }
 
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+pub struct RuneSupply {
+  pub burned: u128,
+  pub circulating: u128,
+  pub open_mint_supply: u128,
+  pub premine: u128,
+}
+
 #[derive(Debug, PartialEq, Serialize, Deserialize)]
 pub struct Sat {
   pub address: Option<String>,
@@ -67,6 +67,21 @@ impl Chain {
     }
   }
 
+  // weighted splits are not yet scheduled for activation on public networks,
+  // where runestones setting the weighted flag remain cenotaphs
+  pub(crate) fn weighted_split_height(self) -> Option<u32> {
+    match self {
+      Self::Regtest => Some(110),
+      Self::Mainnet | Self::Signet | Self::Testnet | Self::Testnet4 => None,
+    }
+  }
+
+  pub(crate) fn weighted_splits(self, height: u32) -> bool {
+    self
+      .weighted_split_height()
+      .is_some_and(|activation_height| height >= activation_height)
+  }
+
   pub(crate) fn genesis_block(self) -> Block {
     bitcoin::blockdata::constants::genesis_block(self.network())
   }
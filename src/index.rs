@@ -5,6 +5,7 @@ use {
       OutPointValue, RuneEntryValue, RuneIdValue, SatPointValue, SatRange, TxidValue,
     },
     event::Event,
+    event_log::EventLog,
     lot::Lot,
     reorg::Reorg,
     updater::Updater,
@@ -43,6 +44,7 @@ pub use self::entry::RuneEntry;
 
 pub(crate) mod entry;
 pub mod event;
+mod event_log;
 mod fetcher;
 mod lot;
 mod reorg;
@@ -55,6 +57,8 @@ pub(crate) mod testing;
 
 const SCHEMA_VERSION: u64 = 31;
 
+const MAX_COMMITMENT_INPUTS: usize = 16;
+
 define_multimap_table! { SAT_TO_SEQUENCE_NUMBER, u64, u32 }
 define_multimap_table! { SCRIPT_PUBKEY_TO_OUTPOINT, &[u8], OutPointValue }
 define_multimap_table! { SEQUENCE_NUMBER_TO_CHILDREN, u32, u32 }
@@ -675,6 +679,7 @@ impl Index {
         index: self,
         outputs_cached: 0,
         outputs_traversed: 0,
+        rune_event_log: None,
         sat_ranges_since_flush: 0,
       };
 
@@ -1004,6 +1009,138 @@ impl Index {
     Ok(Some((RuneId::load(id), entry, parent)))
   }
 
+  /// Check whether `reveal_tx` would commit to `rune` if it were mined in the
+  /// block after bitcoind's current tip, using the same check as the indexer.
+  /// At most `MAX_COMMITMENT_INPUTS` inputs containing the commitment are
+  /// looked up.
+  pub fn check_commitment(
+    &self,
+    reveal_tx: &Transaction,
+    rune: Rune,
+  ) -> Result<api::CommitmentStatus> {
+    let height = u32::try_from(self.client.get_block_count()?)?
+      .checked_add(1)
+      .context("block count overflow")?;
+
+    Self::commitment_status(&self.client, reveal_tx, rune, height, MAX_COMMITMENT_INPUTS)
+  }
+
+  /// Determine whether `tx`, mined at `height`, commits to `rune`, looking up
+  /// at most `limit` inputs whose tapscripts contain the rune's commitment.
+  /// This is the check used when indexing etchings.
+  pub(crate) fn commitment_status(
+    client: &Client,
+    tx: &Transaction,
+    rune: Rune,
+    height: u32,
+    limit: usize,
+  ) -> Result<api::CommitmentStatus> {
+    let commitment = rune.commitment();
+    let required_confirmations = u32::from(Runestone::COMMIT_CONFIRMATIONS);
+
+    let mut status = api::CommitmentStatus {
+      commit_height: None,
+      confirmations: None,
+      input: None,
+      required_confirmations,
+      taproot: false,
+      valid: false,
+    };
+
+    let candidates = tx
+      .input
+      .iter()
+      .filter(|input| {
+        // extracting a tapscript does not indicate that the input being spent
+        // was actually a taproot output. this is checked below, when we load
+        // the output's entry from bitcoind
+        unversioned_leaf_script_from_witness(&input.witness).is_some_and(|tapscript| {
+          tapscript
+            .instructions()
+            // ignore errors, since the extracted script may not be valid
+            .map_while(|instruction| instruction.ok())
+            .any(|instruction| {
+              instruction
+                .push_bytes()
+                .is_some_and(|bytes| bytes.as_bytes() == commitment)
+            })
+        })
+      })
+      .take(limit);
+
+    for input in candidates {
+      let Some(tx_info) = client
+        .get_raw_transaction_info(&input.previous_output.txid, None)
+        .into_option()?
+      else {
+        bail!(
+          "can't get input transaction: {}",
+          input.previous_output.txid
+        );
+      };
+
+      let Some(output) = tx_info.vout.get(input.previous_output.vout.into_usize()) else {
+        continue;
+      };
+
+      let taproot = output.script_pub_key.script()?.is_p2tr();
+
+      let commit_height = match tx_info.blockhash {
+        Some(blockhash) => Some(
+          client
+            .get_block_header_info(&blockhash)
+            .into_option()?
+            .with_context(|| format!("can't get block header: {blockhash}"))?
+            .height,
+        ),
+        None => None,
+      }
+      .map(u32::try_from)
+      .transpose()?;
+
+      let confirmations = commit_height.and_then(|commit_height| {
+        // a commit transaction above `height` can only come from inconsistent
+        // RPC data, e.g. during a reorg, so treat it as unconfirmed rather
+        // than panicking
+        let confirmations = height
+          .checked_sub(commit_height)
+          .map(|depth| depth.saturating_add(1));
+
+        if confirmations.is_none() {
+          log::warn!(
+            "commit transaction {} height {commit_height} is above height {height}",
+            input.previous_output.txid,
+          );
+        }
+
+        confirmations
+      });
+
+      let valid = taproot
+        && confirmations
+          .map(|confirmations| confirmations >= required_confirmations)
+          .unwrap_or_default();
+
+      // prefer the first taproot candidate when no input is valid
+      if valid || status.input.is_none() || (taproot && !status.taproot) {
+        status = api::CommitmentStatus {
+          commit_height,
+          confirmations,
+          input: Some(input.previous_output),
+          required_confirmations,
+          taproot,
+          valid,
+        };
+      }
+
+      if valid {
+        break;
+      }
+    }
+
+    Ok(status)
+  }
+
   pub fn runes(&self) -> Result<Vec<(RuneId, RuneEntry)>> {
     let mut entries = Vec::new();
 
@@ -1066,6 +1203,19 @@ impl Index {
     Ok(((id, balance), len))
   }
 
+  /// Decode every `(RuneId, balance)` pair in an `OUTPOINT_TO_RUNE_BALANCES`
+  /// value, in the order they were encoded.
+  pub fn decode_balances(buffer: &[u8]) -> Result<Vec<(RuneId, u128)>> {
+    let mut balances = Vec::new();
+    let mut i = 0;
+    while i < buffer.len() {
+      let (balance, length) = Index::decode_rune_balance(&buffer[i..])?;
+      i += length;
+      balances.push(balance);
+    }
+    Ok(balances)
+  }
+
   pub fn get_rune_balances_for_output(
     &self,
     outpoint: OutPoint,
@@ -1084,17 +1234,12 @@ impl Index {
       return Ok(Some(BTreeMap::new()));
     };
 
-    let balances_buffer = balances.value();
-
-    let mut balances = BTreeMap::new();
-    let mut i = 0;
-    while i < balances_buffer.len() {
-      let ((id, amount), length) = Index::decode_rune_balance(&balances_buffer[i..]).unwrap();
-      i += length;
+    let mut piles = BTreeMap::new();
 
+    for (id, amount) in Index::decode_balances(balances.value())? {
       let entry = RuneEntry::load(id_to_rune_entries.get(id.store())?.unwrap().value());
 
-      balances.insert(
+      piles.insert(
         entry.spaced_rune,
         Pile {
           amount,
@@ -1104,7 +1249,7 @@ impl Index {
       );
     }
 
-    Ok(Some(balances))
+    Ok(Some(piles))
   }
 
   pub fn get_rune_balance_map(&self) -> Result<BTreeMap<SpacedRune, BTreeMap<OutPoint, Pile>>> {
@@ -1173,16 +1318,7 @@ impl Index {
     {
       let (outpoint, balances_buffer) = entry?;
       let outpoint = OutPoint::load(*outpoint.value());
-      let balances_buffer = balances_buffer.value();
-
-      let mut balances = Vec::new();
-      let mut i = 0;
-      while i < balances_buffer.len() {
-        let ((id, balance), length) = Index::decode_rune_balance(&balances_buffer[i..]).unwrap();
-        i += length;
-        balances.push((id, balance));
-      }
-
+      let balances = Index::decode_balances(balances_buffer.value())?;
       result.push((outpoint, balances));
     }
 
@@ -1721,6 +1857,30 @@ impl Index {
       .into_option()
   }
 
+  // runestones are deciphered as of the transaction's confirmation height or,
+  // if it is unconfirmed, the height of the next block
+  pub(crate) fn weighted_splits(&self, txid: Txid) -> Result<bool> {
+    let chain = self.settings.chain();
+
+    if chain.weighted_split_height().is_none() {
+      return Ok(false);
+    }
+
+    let height = match self
+      .get_transaction_info(&txid)?
+      .and_then(|info| info.blockhash)
+    {
+      Some(blockhash) => self
+        .block_header_info(blockhash)?
+        .with_context(|| format!("can't get block header: {blockhash}"))?
+        .height
+        .try_into()?,
+      None => self.block_count()?,
+    };
+
+    Ok(chain.weighted_splits(height))
+  }
+
   pub fn get_transaction(&self, txid: Txid) -> Result<Option<Transaction>> {
     if txid == self.genesis_block_coinbase_txid {
       return Ok(Some(self.genesis_block_coinbase_transaction.clone()));
@@ -2603,7 +2763,10 @@ impl Index {
 
 #[cfg(test)]
 mod tests {
-  use {super::*, crate::index::testing::Context};
+  use {
+    super::*,
+    crate::index::{event_log::EventLogEntry, testing::Context},
+  };
 
   #[test]
   fn height_limit() {
@@ -6681,6 +6844,128 @@ mod tests {
     );
   }
 
+  #[test]
+  fn rune_event_log() {
+    const RUNE: u128 = 99246114928149462;
+
+    let tempdir = TempDir::new().unwrap();
+
+    let path = tempdir.path().join("events.ndjson");
+
+    let context = Context::builder()
+      .arg("--index-runes")
+      .arg(format!("--rune-event-log={}", path.display()))
+      .build();
+
+    let (txid0, id) = context.etch(
+      Runestone {
+        etching: Some(Etching {
+          rune: Some(Rune(RUNE)),
+          terms: Some(Terms {
+            amount: Some(1000),
+            cap: Some(100),
+            ..default()
+          }),
+          ..default()
+        }),
+        ..default()
+      },
+      1,
+    );
+
+    let txid1 = context.core.broadcast_tx(TransactionTemplate {
+      inputs: &[(2, 0, 0, Witness::new())],
+      op_return: Some(
+        Runestone {
+          mint: Some(id),
+          ..default()
+        }
+        .encipher(),
+      ),
+      ..default()
+    });
+
+    context.mine_blocks(1);
+
+    let etch_height = u32::try_from(id.block).unwrap();
+    let mint_height = context.index.block_height().unwrap().unwrap().n();
+
+    let etch_hash = context
+      .index
+      .block_hash(Some(etch_height))
+      .unwrap()
+      .unwrap();
+    let mint_hash = context
+      .index
+      .block_hash(Some(mint_height))
+      .unwrap()
+      .unwrap();
+
+    pretty_assert_eq!(
+      fs::read_to_string(&path)
+        .unwrap()
+        .lines()
+        .map(|line| serde_json::from_str(line).unwrap())
+        .collect::<Vec<EventLogEntry>>(),
+      [
+        EventLogEntry {
+          block_hash: etch_hash,
+          event: Event::RuneEtched {
+            block_height: etch_height,
+            txid: txid0,
+            rune_id: id,
+          },
+        },
+        EventLogEntry {
+          block_hash: mint_hash,
+          event: Event::RuneMinted {
+            block_height: mint_height,
+            txid: txid1,
+            rune_id: id,
+            amount: 1000,
+          },
+        },
+        EventLogEntry {
+          block_hash: mint_hash,
+          event: Event::RuneTransferred {
+            block_height: mint_height,
+            txid: txid1,
+            rune_id: id,
+            amount: 1000,
+            outpoint: OutPoint {
+              txid: txid1,
+              vout: 0,
+            },
+          },
+        },
+      ],
+    );
+  }
+
+  #[test]
+  fn rune_event_log_is_not_created_without_index_runes() {
+    let tempdir = TempDir::new().unwrap();
+
+    let path = tempdir.path().join("events.ndjson");
+
+    let context = Context::builder()
+      .arg(format!("--rune-event-log={}", path.display()))
+      .build();
+
+    context.etch(
+      Runestone {
+        etching: Some(Etching {
+          rune: Some(Rune(99246114928149462)),
+          ..default()
+        }),
+        ..default()
+      },
+      1,
+    );
+
+    assert!(!path.exists());
+  }
+
   #[test]
   fn rune_event_sender_channel() {
     const RUNE: u128 = 99246114928149462;
@@ -6918,6 +7203,269 @@ mod tests {
     );
   }
 
+  #[test]
+  fn check_commitment() {
+    const RUNE: u128 = 99246114928149462;
+
+    let context = Context::builder().build();
+
+    let rune = Rune(RUNE);
+
+    context.mine_blocks(1);
+
+    let commit = context.core.broadcast_tx(TransactionTemplate {
+      inputs: &[(1, 0, 0, Witness::new())],
+      p2tr: true,
+      ..default()
+    });
+
+    context.mine_blocks(1);
+
+    let mut witness = Witness::new();
+
+    witness.push(
+      script::Builder::new()
+        .push_slice::<&script::PushBytes>(rune.commitment().as_slice().try_into().unwrap())
+        .into_script(),
+    );
+
+    witness.push([]);
+
+    let reveal = Transaction {
+      version: Version(2),
+      lock_time: LockTime::ZERO,
+      input: vec![TxIn {
+        previous_output: OutPoint {
+          txid: commit,
+          vout: 0,
+        },
+        script_sig: ScriptBuf::new(),
+        sequence: Sequence::ENABLE_RBF_NO_LOCKTIME,
+        witness,
+      }],
+      output: Vec::new(),
+    };
+
+    assert_eq!(
+      context.index.check_commitment(&reveal, rune).unwrap(),
+      api::CommitmentStatus {
+        commit_height: Some(2),
+        confirmations: Some(2),
+        input: Some(OutPoint {
+          txid: commit,
+          vout: 0,
+        }),
+        required_confirmations: Runestone::COMMIT_CONFIRMATIONS.into(),
+        taproot: true,
+        valid: false,
+      }
+    );
+
+    context.mine_blocks(4);
+
+    assert_eq!(
+      context.index.check_commitment(&reveal, rune).unwrap(),
+      api::CommitmentStatus {
+        commit_height: Some(2),
+        confirmations: Some(6),
+        input: Some(OutPoint {
+          txid: commit,
+          vout: 0,
+        }),
+        required_confirmations: Runestone::COMMIT_CONFIRMATIONS.into(),
+        taproot: true,
+        valid: true,
+      }
+    );
+
+    assert_eq!(
+      context
+        .index
+        .check_commitment(&reveal, Rune(RUNE + 1))
+        .unwrap(),
+      api::CommitmentStatus {
+        commit_height: None,
+        confirmations: None,
+        input: None,
+        required_confirmations: Runestone::COMMIT_CONFIRMATIONS.into(),
+        taproot: false,
+        valid: false,
+      }
+    );
+  }
+
+  #[test]
+  fn weighted_splits_use_transaction_confirmation_height() {
+    let context = Context::builder().build();
+
+    context.mine_blocks(1);
+
+    let confirmed = context.core.broadcast_tx(TransactionTemplate {
+      inputs: &[(1, 0, 0, Witness::new())],
+      ..default()
+    });
+
+    context.mine_blocks(Chain::Regtest.weighted_split_height().unwrap().into());
+
+    assert!(!context.index.weighted_splits(confirmed).unwrap());
+
+    let unconfirmed = context.core.broadcast_tx(TransactionTemplate {
+      inputs: &[(2, 0, 0, Witness::new())],
+      ..default()
+    });
+
+    assert!(context.index.weighted_splits(unconfirmed).unwrap());
+  }
+
+  #[test]
+  fn transactions_without_runes_have_no_rune_effects() {
+    const RUNE: u128 = 99246114928149462;
+
+    let (event_sender, mut event_receiver) = tokio::sync::mpsc::channel(1024);
+    let context = Context::builder()
+      .arg("--index-runes")
+      .event_sender(event_sender)
+      .build();
+
+    let (txid0, id) = context.etch(
+      Runestone {
+        etching: Some(Etching {
+          rune: Some(Rune(RUNE)),
+          premine: Some(100),
+          ..default()
+        }),
+        ..default()
+      },
+      1,
+    );
+
+    while event_receiver.try_recv().is_ok() {}
+
+    context.core.broadcast_tx(TransactionTemplate {
+      inputs: &[(2, 0, 0, Witness::new())],
+      ..default()
+    });
+
+    context.mine_blocks(1);
+
+    assert!(event_receiver.try_recv().is_err());
+
+    assert_eq!(
+      context.index.get_rune_balances().unwrap(),
+      [(
+        OutPoint {
+          txid: txid0,
+          vout: 0,
+        },
+        vec![(id, 100)],
+      )],
+    );
+
+    let txid1 = context.core.broadcast_tx(TransactionTemplate {
+      inputs: &[(id.block.try_into().unwrap(), 1, 0, Witness::new())],
+      ..default()
+    });
+
+    context.mine_blocks(1);
+
+    let outpoint = OutPoint {
+      txid: txid1,
+      vout: 0,
+    };
+
+    assert_eq!(
+      event_receiver.try_recv().unwrap(),
+      Event::RuneTransferred {
+        amount: 100,
+        block_height: context.index.block_height().unwrap().unwrap().n(),
+        outpoint,
+        rune_id: id,
+        txid: txid1,
+      },
+    );
+
+    assert!(event_receiver.try_recv().is_err());
+
+    assert_eq!(
+      context.index.get_rune_balances().unwrap(),
+      [(outpoint, vec![(id, 100)])],
+    );
+  }
+
+  #[test]
+  fn rune_events_for_transaction_which_etches_and_mints() {
+    const RUNE: u128 = 99246114928149462;
+
+    let (event_sender, mut event_receiver) = tokio::sync::mpsc::channel(1024);
+    let context = Context::builder()
+      .arg("--index-runes")
+      .event_sender(event_sender)
+      .build();
+
+    let (_, id0) = context.etch(
+      Runestone {
+        etching: Some(Etching {
+          rune: Some(Rune(RUNE)),
+          terms: Some(Terms {
+            amount: Some(1000),
+            cap: Some(100),
+            ..default()
+          }),
+          ..default()
+        }),
+        ..default()
+      },
+      1,
+    );
+
+    while event_receiver.try_recv().is_ok() {}
+
+    let (txid1, id1) = context.etch(
+      Runestone {
+        etching: Some(Etching {
+          rune: Some(Rune(RUNE + 1)),
+          ..default()
+        }),
+        mint: Some(id0),
+        ..default()
+      },
+      1,
+    );
+
+    let mut events = Vec::new();
+
+    while let Ok(event) = event_receiver.try_recv() {
+      events.push(event);
+    }
+
+    pretty_assert_eq!(
+      events,
+      [
+        Event::RuneMinted {
+          block_height: id1.block.try_into().unwrap(),
+          txid: txid1,
+          rune_id: id0,
+          amount: 1000,
+        },
+        Event::RuneEtched {
+          block_height: id1.block.try_into().unwrap(),
+          txid: txid1,
+          rune_id: id1,
+        },
+        Event::RuneTransferred {
+          block_height: id1.block.try_into().unwrap(),
+          txid: txid1,
+          rune_id: id0,
+          amount: 1000,
+          outpoint: OutPoint {
+            txid: txid1,
+            vout: 0,
+          },
+        },
+      ]
+    );
+  }
+
   #[test]
   fn assert_schema_statistic_key_is_zero() {
     // other schema statistic keys may change when the schema changes, but for
@@ -6925,4 +7473,37 @@ mod tests {
     // zero
     assert_eq!(Statistic::Schema.key(), 0);
   }
+
+  #[test]
+  fn decode_balances_round_trips() {
+    let balances = vec![
+      (RuneId { block: 1, tx: 0 }, 0),
+      (
+        RuneId {
+          block: 840000,
+          tx: 7,
+        },
+        1000,
+      ),
+      (
+        RuneId {
+          block: 840001,
+          tx: 1,
+        },
+        u128::MAX,
+      ),
+    ];
+
+    let mut buffer = Vec::new();
+
+    for (id, balance) in &balances {
+      Index::encode_rune_balance(*id, *balance, &mut buffer);
+    }
+
+    assert_eq!(Index::decode_balances(&buffer).unwrap(), balances);
+
+    assert_eq!(Index::decode_balances(&[]).unwrap(), Vec::new());
+
+    assert!(Index::decode_balances(&buffer[..buffer.len() - 1]).is_err());
+  }
 }
@@ -82,22 +82,33 @@ impl RuneEntry {
     Ok(terms.amount.unwrap_or_default())
   }
 
+  // etchings whose maximum supply overflows a u128 are cenotaphs, so these
+  // should never saturate, but entries are decoded from the index and must
+  // not wrap if they do
   pub fn supply(&self) -> u128 {
-    self.premine
-      + self.mints
-        * self
+    self.premine.saturating_add(
+      self.mints.saturating_mul(
+        self
           .terms
           .and_then(|terms| terms.amount)
-          .unwrap_or_default()
+          .unwrap_or_default(),
+      ),
+    )
   }
 
   pub fn max_supply(&self) -> u128 {
-    self.premine
-      + self.terms.and_then(|terms| terms.cap).unwrap_or_default()
-        * self
-          .terms
-          .and_then(|terms| terms.amount)
-          .unwrap_or_default()
+    self.premine.saturating_add(
+      self
+        .terms
+        .and_then(|terms| terms.cap)
+        .unwrap_or_default()
+        .saturating_mul(
+          self
+            .terms
+            .and_then(|terms| terms.amount)
+            .unwrap_or_default(),
+        ),
+    )
   }
 
   pub fn pile(&self, amount: u128) -> Pile {
@@ -922,4 +933,37 @@ mod tests {
       1001
     );
   }
+
+  #[test]
+  fn supply_saturates() {
+    assert_eq!(
+      RuneEntry {
+        terms: Some(Terms {
+          amount: Some(u128::MAX),
+          cap: Some(2),
+          ..default()
+        }),
+        mints: 2,
+        premine: 1,
+        ..default()
+      }
+      .supply(),
+      u128::MAX
+    );
+
+    assert_eq!(
+      RuneEntry {
+        terms: Some(Terms {
+          amount: Some(u128::MAX / 2),
+          cap: Some(2),
+          ..default()
+        }),
+        mints: 2,
+        premine: 2,
+        ..default()
+      }
+      .max_supply(),
+      u128::MAX
+    );
+  }
 }
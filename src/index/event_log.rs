@@ -0,0 +1,51 @@
+use super::*;
+
+/// A line of the rune event log. Lines are written as each block is indexed,
+/// before it is committed, so a block is logged again if indexing stops
+/// before its commit. Lines include the block's hash, so that repeated
+/// blocks, and blocks which are later reorged out, can be told apart.
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+pub(crate) struct EventLogEntry {
+  pub(crate) block_hash: BlockHash,
+  pub(crate) event: Event,
+}
+
+/// Appends rune events to a file as newline-delimited JSON. Inscription
+/// events are not logged.
+pub(crate) struct EventLog {
+  file: File,
+}
+
+impl EventLog {
+  pub(crate) fn open(path: &Path) -> Result<Self> {
+    let file = fs::OpenOptions::new()
+      .create(true)
+      .append(true)
+      .open(path)
+      .with_context(|| format!("failed to open rune event log `{}`", path.display()))?;
+
+    Ok(Self { file })
+  }
+
+  pub(crate) fn write(
+    &mut self,
+    block_hash: BlockHash,
+    events: impl IntoIterator<Item = Event>,
+  ) -> Result {
+    let mut writer = BufWriter::new(&mut self.file);
+
+    for event in events {
+      serde_json::to_writer(&mut writer, &EventLogEntry { block_hash, event })?;
+      writer.write_all(b"\n")?;
+    }
+
+    writer.flush()?;
+
+    Ok(())
+  }
+
+  pub(crate) fn sync(&self) -> Result {
+    self.file.sync_data()?;
+    Ok(())
+  }
+}
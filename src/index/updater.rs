@@ -37,6 +37,7 @@ pub(crate) struct Updater<'index> {
   pub(super) index: &'index Index,
   pub(super) outputs_cached: u64,
   pub(super) outputs_traversed: u64,
+  pub(super) rune_event_log: Option<EventLog>,
   pub(super) sat_ranges_since_flush: u64,
 }
 
@@ -381,13 +382,38 @@ impl Updater<'_> {
         sequence_number_to_rune_id: &mut sequence_number_to_rune_id,
         statistic_to_count: &mut statistic_to_count,
         transaction_id_to_rune: &mut transaction_id_to_rune,
+        weighted_splits: self.index.settings.chain().weighted_splits(self.height),
       };
 
+      let rune_event_log = self.index.settings.rune_event_log();
+
+      let mut events = Vec::new();
+
       for (i, (tx, txid)) in block.txdata.iter().enumerate() {
-        rune_updater.index_runes(u32::try_from(i).unwrap(), tx, *txid)?;
+        let effects = rune_updater.index_runes(u32::try_from(i).unwrap(), tx, *txid)?;
+
+        if rune_event_log.is_some() {
+          events.extend(effects.events(self.height, *txid));
+        }
       }
 
       rune_updater.update()?;
+
+      // events are logged before the block is committed, so that they are
+      // written at least once
+      if let Some(path) = rune_event_log
+        && !events.is_empty()
+      {
+        if self.rune_event_log.is_none() {
+          self.rune_event_log = Some(EventLog::open(path)?);
+        }
+
+        self
+          .rune_event_log
+          .as_mut()
+          .unwrap()
+          .write(block.header.block_hash(), events)?;
+      }
     }
 
     height_to_block_header.insert(&self.height, &block.header.store())?;
@@ -865,6 +891,13 @@ impl Updater<'_> {
     Index::increment_statistic(&wtx, Statistic::SatRanges, self.sat_ranges_since_flush)?;
     self.sat_ranges_since_flush = 0;
     Index::increment_statistic(&wtx, Statistic::Commits, 1)?;
+
+    // logged rune events must be durable before the blocks which produced
+    // them are committed
+    if let Some(log) = &self.rune_event_log {
+      log.sync()?;
+    }
+
     wtx.commit()?;
 
     // Commit twice since due to a bug redb will only reuse pages freed in the
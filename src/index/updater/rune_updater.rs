@@ -1,5 +1,59 @@
 use super::*;
 
+/// The rune effects of a single transaction, in the order they are reported
+/// as events.
+#[derive(Debug, Default)]
+pub(crate) struct TxRuneEffects {
+  pub(super) minted: Option<(RuneId, u128)>,
+  pub(super) etched: Option<RuneId>,
+  pub(super) transferred: Vec<(OutPoint, RuneId, u128)>,
+  pub(super) burned: Vec<(RuneId, u128)>,
+}
+
+impl TxRuneEffects {
+  pub(super) fn events(&self, block_height: u32, txid: Txid) -> Vec<Event> {
+    let mut events = Vec::new();
+
+    if let Some((rune_id, amount)) = self.minted {
+      events.push(Event::RuneMinted {
+        block_height,
+        txid,
+        rune_id,
+        amount,
+      });
+    }
+
+    if let Some(rune_id) = self.etched {
+      events.push(Event::RuneEtched {
+        block_height,
+        txid,
+        rune_id,
+      });
+    }
+
+    for &(outpoint, rune_id, amount) in &self.transferred {
+      events.push(Event::RuneTransferred {
+        outpoint,
+        block_height,
+        txid,
+        rune_id,
+        amount,
+      });
+    }
+
+    for &(rune_id, amount) in &self.burned {
+      events.push(Event::RuneBurned {
+        block_height,
+        txid,
+        rune_id,
+        amount,
+      });
+    }
+
+    events
+  }
+}
+
 pub(super) struct RuneUpdater<'a, 'tx, 'client> {
   pub(super) block_time: u32,
   pub(super) burned: HashMap<RuneId, Lot>,
@@ -15,14 +69,27 @@ pub(super) struct RuneUpdater<'a, 'tx, 'client> {
   pub(super) sequence_number_to_rune_id: &'a mut Table<'tx, u32, RuneIdValue>,
   pub(super) statistic_to_count: &'a mut Table<'tx, u64, u64>,
   pub(super) transaction_id_to_rune: &'a mut Table<'tx, &'static TxidValue, u128>,
+  pub(super) weighted_splits: bool,
 }
 
 impl RuneUpdater<'_, '_, '_> {
-  pub(super) fn index_runes(&mut self, tx_index: u32, tx: &Transaction, txid: Txid) -> Result<()> {
-    let artifact = Runestone::decipher(tx);
+  pub(super) fn index_runes(
+    &mut self,
+    tx_index: u32,
+    tx: &Transaction,
+    txid: Txid,
+  ) -> Result<TxRuneEffects> {
+    let artifact = Runestone::decipher(tx, self.weighted_splits);
 
     let mut unallocated = self.unallocated(tx)?;
 
+    let mut effects = TxRuneEffects::default();
+
+    // transactions with no runestone and no input runes have no rune effects
+    if artifact.is_none() && unallocated.is_empty() {
+      return Ok(effects);
+    }
+
     let mut allocated: Vec<HashMap<RuneId, Lot>> = vec![HashMap::new(); tx.output.len()];
 
     if let Some(artifact) = &artifact {
@@ -30,15 +97,7 @@ impl RuneUpdater<'_, '_, '_> {
         && let Some(amount) = self.mint(id)?
       {
         *unallocated.entry(id).or_default() += amount;
-
-        if let Some(sender) = self.event_sender {
-          sender.blocking_send(Event::RuneMinted {
-            block_height: self.height,
-            txid,
-            rune_id: id,
-            amount: amount.n(),
-          })?;
-        }
+        effects.minted = Some((id, amount.n()));
       }
 
       let etched = self.etched(tx_index, tx, artifact)?;
@@ -90,7 +149,45 @@ impl RuneUpdater<'_, '_, '_> {
               .collect::<Vec<usize>>();
 
             if !destinations.is_empty() {
-              if amount == 0 {
+              // output values are bounded by the maximum money supply, so
+              // neither their sum nor any product below can overflow a u128
+              let weighted_total = (amount == 0 && runestone.weighted)
+                .then(|| {
+                  destinations
+                    .iter()
+                    .map(|output| u128::from(tx.output[*output].value.to_sat()))
+                    .sum::<u128>()
+                })
+                .filter(|total| *total > 0);
+
+              if let Some(total) = weighted_total {
+                // if amount is zero and the runestone is weighted, divide
+                // balance between eligible outputs in proportion to their
+                // values, splitting the balance into quotient and remainder so
+                // each share is floor(balance * value / total) without overflow
+                let quotient = (*balance / total).n();
+                let remainder = (*balance % total).n();
+
+                let shares = destinations
+                  .iter()
+                  .map(|output| {
+                    let value = u128::from(tx.output[*output].value.to_sat());
+                    quotient * value + remainder * value / total
+                  })
+                  .collect::<Vec<u128>>();
+
+                // rounding leaves fewer runes than destinations, which are
+                // assigned one each to the first outputs
+                let leftover = usize::try_from(balance.n() - shares.iter().sum::<u128>()).unwrap();
+
+                for (i, (output, share)) in destinations.iter().zip(shares).enumerate() {
+                  allocate(
+                    balance,
+                    Lot(if i < leftover { share + 1 } else { share }),
+                    *output,
+                  );
+                }
+              } else if amount == 0 {
                 // if amount is zero, divide balance between eligible outputs
                 let amount = *balance / destinations.len() as u128;
                 let remainder = usize::try_from(*balance % destinations.len() as u128).unwrap();
@@ -124,6 +221,7 @@ impl RuneUpdater<'_, '_, '_> {
 
       if let Some((id, rune)) = etched {
         self.create_rune_entry(txid, artifact, id, rune)?;
+        effects.etched = Some(id);
       }
     }
 
@@ -197,16 +295,7 @@ impl RuneUpdater<'_, '_, '_> {
 
       for (id, balance) in balances {
         Index::encode_rune_balance(id, balance.n(), &mut buffer);
-
-        if let Some(sender) = self.event_sender {
-          sender.blocking_send(Event::RuneTransferred {
-            outpoint,
-            block_height: self.height,
-            txid,
-            rune_id: id,
-            amount: balance.0,
-          })?;
-        }
+        effects.transferred.push((outpoint, id, balance.n()));
       }
 
       self
@@ -217,14 +306,20 @@ impl RuneUpdater<'_, '_, '_> {
     // increment entries with burned runes
     for (id, amount) in burned {
       *self.burned.entry(id).or_default() += amount;
+      effects.burned.push((id, amount.n()));
+    }
+
+    effects.burned.sort();
 
-      if let Some(sender) = self.event_sender {
-        sender.blocking_send(Event::RuneBurned {
-          block_height: self.height,
-          txid,
-          rune_id: id,
-          amount: amount.n(),
-        })?;
+    self.send_events(txid, &effects)?;
+
+    Ok(effects)
+  }
+
+  fn send_events(&self, txid: Txid, effects: &TxRuneEffects) -> Result {
+    if let Some(sender) = self.event_sender {
+      for event in effects.events(self.height, txid) {
+        sender.blocking_send(event)?;
       }
     }
 
@@ -308,14 +403,6 @@ impl RuneUpdater<'_, '_, '_> {
 
     self.id_to_entry.insert(id.store(), entry.store())?;
 
-    if let Some(sender) = self.event_sender {
-      sender.blocking_send(Event::RuneEtched {
-        block_height: self.height,
-        txid,
-        rune_id: id,
-      })?;
-    }
-
     let inscription_id = InscriptionId { txid, index: 0 };
 
     if let Some(sequence_number) = self
@@ -400,70 +487,7 @@ impl RuneUpdater<'_, '_, '_> {
   }
 
   fn tx_commits_to_rune(&self, tx: &Transaction, rune: Rune) -> Result<bool> {
-    let commitment = rune.commitment();
-
-    for input in &tx.input {
-      // extracting a tapscript does not indicate that the input being spent
-      // was actually a taproot output. this is checked below, when we load the
-      // output's entry from the database
-      let Some(tapscript) = unversioned_leaf_script_from_witness(&input.witness) else {
-        continue;
-      };
-
-      for instruction in tapscript.instructions() {
-        // ignore errors, since the extracted script may not be valid
-        let Ok(instruction) = instruction else {
-          break;
-        };
-
-        let Some(pushbytes) = instruction.push_bytes() else {
-          continue;
-        };
-
-        if pushbytes.as_bytes() != commitment {
-          continue;
-        }
-
-        let Some(tx_info) = self
-          .client
-          .get_raw_transaction_info(&input.previous_output.txid, None)
-          .into_option()?
-        else {
-          panic!(
-            "can't get input transaction: {}",
-            input.previous_output.txid
-          );
-        };
-
-        let taproot = tx_info.vout[input.previous_output.vout.into_usize()]
-          .script_pub_key
-          .script()?
-          .is_p2tr();
-
-        if !taproot {
-          continue;
-        }
-
-        let commit_tx_height = self
-          .client
-          .get_block_header_info(&tx_info.blockhash.unwrap())
-          .into_option()?
-          .unwrap()
-          .height;
-
-        let confirmations = self
-          .height
-          .checked_sub(commit_tx_height.try_into().unwrap())
-          .unwrap()
-          + 1;
-
-        if confirmations >= u32::from(Runestone::COMMIT_CONFIRMATIONS) {
-          return Ok(true);
-        }
-      }
-    }
-
-    Ok(false)
+    Ok(Index::commitment_status(self.client, tx, rune, self.height, usize::MAX)?.valid)
   }
 
   fn unallocated(&mut self, tx: &Transaction) -> Result<HashMap<RuneId, Lot>> {
@@ -476,11 +500,7 @@ impl RuneUpdater<'_, '_, '_> {
         .outpoint_to_balances
         .remove(&input.previous_output.store())?
       {
-        let buffer = guard.value();
-        let mut i = 0;
-        while i < buffer.len() {
-          let ((id, balance), len) = Index::decode_rune_balance(&buffer[i..]).unwrap();
-          i += len;
+        for (id, balance) in Index::decode_balances(guard.value())? {
           *unallocated.entry(id).or_default() += balance;
         }
       }
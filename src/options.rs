@@ -73,6 +73,11 @@ pub struct Options {
     help = "Do not index inscriptions."
   )]
   pub(crate) no_index_inscriptions: bool,
+  #[arg(
+    long,
+    help = "Append rune events to <RUNE_EVENT_LOG> as newline-delimited JSON when indexing runes. Blocks are logged before they are committed, so a block may be logged more than once. Each line includes the block hash, so repeated and reorged blocks can be discarded."
+  )]
+  pub(crate) rune_event_log: Option<PathBuf>,
   #[arg(
     long,
     help = "Require basic HTTP authentication with <SERVER_PASSWORD>. Credentials are sent in cleartext. Consider using authentication in conjunction with HTTPS."
@@ -3118,6 +3118,238 @@ mod tests {
     );
   }
 
+  #[test]
+  fn weighted_split() {
+    let context = Context::builder().arg("--index-runes").build();
+
+    context.mine_blocks(Chain::Regtest.weighted_split_height().unwrap().into());
+
+    let (txid0, id) = context.etch(
+      Runestone {
+        edicts: vec![Edict {
+          id: RuneId::default(),
+          amount: 100,
+          output: 0,
+        }],
+        etching: Some(Etching {
+          rune: Some(Rune(RUNE)),
+          premine: Some(100),
+          ..default()
+        }),
+        ..default()
+      },
+      1,
+    );
+
+    let entry = RuneEntry {
+      block: id.block,
+      etching: txid0,
+      spaced_rune: SpacedRune {
+        rune: Rune(RUNE),
+        spacers: 0,
+      },
+      premine: 100,
+      timestamp: id.block,
+      ..default()
+    };
+
+    context.assert_runes(
+      [(id, entry)],
+      [(
+        OutPoint {
+          txid: txid0,
+          vout: 0,
+        },
+        vec![(id, 100)],
+      )],
+    );
+
+    let txid1 = context.core.broadcast_tx(TransactionTemplate {
+      inputs: &[(id.block.try_into().unwrap(), 1, 0, Witness::new())],
+      outputs: 2,
+      output_values: &[1000, 3000],
+      op_return: Some(
+        Runestone {
+          edicts: vec![Edict {
+            id,
+            amount: 0,
+            output: 3,
+          }],
+          weighted: true,
+          ..default()
+        }
+        .encipher(),
+      ),
+      ..default()
+    });
+
+    context.mine_blocks(1);
+
+    context.assert_runes(
+      [(id, entry)],
+      [
+        (
+          OutPoint {
+            txid: txid1,
+            vout: 0,
+          },
+          vec![(id, 25)],
+        ),
+        (
+          OutPoint {
+            txid: txid1,
+            vout: 1,
+          },
+          vec![(id, 75)],
+        ),
+      ],
+    );
+  }
+
+  #[test]
+  fn weighted_split_assigns_rounding_leftovers_to_first_outputs() {
+    let context = Context::builder().arg("--index-runes").build();
+
+    context.mine_blocks(Chain::Regtest.weighted_split_height().unwrap().into());
+
+    let (txid0, id) = context.etch(
+      Runestone {
+        edicts: vec![Edict {
+          id: RuneId::default(),
+          amount: 10,
+          output: 0,
+        }],
+        etching: Some(Etching {
+          rune: Some(Rune(RUNE)),
+          premine: Some(10),
+          ..default()
+        }),
+        ..default()
+      },
+      1,
+    );
+
+    let txid1 = context.core.broadcast_tx(TransactionTemplate {
+      inputs: &[(id.block.try_into().unwrap(), 1, 0, Witness::new())],
+      outputs: 2,
+      output_values: &[1, 2],
+      op_return: Some(
+        Runestone {
+          edicts: vec![Edict {
+            id,
+            amount: 0,
+            output: 3,
+          }],
+          weighted: true,
+          ..default()
+        }
+        .encipher(),
+      ),
+      ..default()
+    });
+
+    context.mine_blocks(1);
+
+    context.assert_runes(
+      [(
+        id,
+        RuneEntry {
+          block: id.block,
+          etching: txid0,
+          spaced_rune: SpacedRune {
+            rune: Rune(RUNE),
+            spacers: 0,
+          },
+          premine: 10,
+          timestamp: id.block,
+          ..default()
+        },
+      )],
+      [
+        (
+          OutPoint {
+            txid: txid1,
+            vout: 0,
+          },
+          vec![(id, 4)],
+        ),
+        (
+          OutPoint {
+            txid: txid1,
+            vout: 1,
+          },
+          vec![(id, 6)],
+        ),
+      ],
+    );
+  }
+
+  #[test]
+  fn weighted_flag_before_activation_produces_cenotaph() {
+    let context = Context::builder().arg("--index-runes").build();
+
+    let (txid0, id) = context.etch(
+      Runestone {
+        edicts: vec![Edict {
+          id: RuneId::default(),
+          amount: 100,
+          output: 0,
+        }],
+        etching: Some(Etching {
+          rune: Some(Rune(RUNE)),
+          premine: Some(100),
+          ..default()
+        }),
+        ..default()
+      },
+      1,
+    );
+
+    context.core.broadcast_tx(TransactionTemplate {
+      inputs: &[(id.block.try_into().unwrap(), 1, 0, Witness::new())],
+      outputs: 2,
+      output_values: &[1000, 3000],
+      op_return: Some(
+        Runestone {
+          edicts: vec![Edict {
+            id,
+            amount: 0,
+            output: 3,
+          }],
+          weighted: true,
+          ..default()
+        }
+        .encipher(),
+      ),
+      ..default()
+    });
+
+    context.mine_blocks(1);
+
+    assert!(
+      context.index.block_count().unwrap() <= Chain::Regtest.weighted_split_height().unwrap()
+    );
+
+    context.assert_runes(
+      [(
+        id,
+        RuneEntry {
+          block: id.block,
+          burned: 100,
+          etching: txid0,
+          spaced_rune: SpacedRune {
+            rune: Rune(RUNE),
+            spacers: 0,
+          },
+          premine: 100,
+          timestamp: id.block,
+          ..default()
+        },
+      )],
+      [],
+    );
+  }
+
   #[test]
   fn split_with_preceding_edict() {
     let context = Context::builder().arg("--index-runes").build();
@@ -5839,6 +6071,92 @@ mod tests {
     context.assert_runes([], []);
   }
 
+  #[test]
+  fn commits_with_exactly_commit_confirmations_are_valid() {
+    let context = Context::builder().arg("--index-runes").build();
+
+    let block_count = context.index.block_count().unwrap().into_usize();
+
+    context.mine_blocks(1);
+
+    context.core.broadcast_tx(TransactionTemplate {
+      inputs: &[(block_count, 0, 0, Witness::new())],
+      p2tr: true,
+      ..default()
+    });
+
+    // the commit transaction's block and the reveal transaction's block both
+    // count as confirmations
+    context.mine_blocks((Runestone::COMMIT_CONFIRMATIONS - 1).into());
+
+    let mut witness = Witness::new();
+
+    let runestone = Runestone {
+      etching: Some(Etching {
+        rune: Some(Rune(RUNE)),
+        terms: Some(Terms {
+          amount: Some(1000),
+          ..default()
+        }),
+        ..default()
+      }),
+      ..default()
+    };
+
+    let tapscript = script::Builder::new()
+      .push_slice::<&PushBytes>(
+        runestone
+          .etching
+          .unwrap()
+          .rune
+          .unwrap()
+          .commitment()
+          .as_slice()
+          .try_into()
+          .unwrap(),
+      )
+      .into_script();
+
+    witness.push(tapscript);
+
+    witness.push([]);
+
+    let txid = context.core.broadcast_tx(TransactionTemplate {
+      inputs: &[(block_count + 1, 1, 0, witness)],
+      op_return: Some(runestone.encipher()),
+      outputs: 1,
+      ..default()
+    });
+
+    context.mine_blocks(1);
+
+    let id = RuneId {
+      block: u64::try_from(block_count + usize::from(Runestone::COMMIT_CONFIRMATIONS)).unwrap(),
+      tx: 1,
+    };
+
+    context.assert_runes(
+      [(
+        id,
+        RuneEntry {
+          block: id.block,
+          etching: txid,
+          spaced_rune: SpacedRune {
+            rune: Rune(RUNE),
+            spacers: 0,
+          },
+          terms: Some(Terms {
+            amount: Some(1000),
+            ..default()
+          }),
+          timestamp: id.block,
+          ..default()
+        },
+      )],
+      [],
+    );
+  }
+
   #[test]
   fn immature_commits_are_not_valid_even_when_bitcoind_is_ahead() {
     let context = Context::builder().arg("--index-runes").build();
@@ -5901,6 +6219,74 @@ mod tests {
     context.assert_runes([], []);
   }
 
+  #[test]
+  fn commits_above_indexing_height_are_not_valid() {
+    let context = Context::builder().arg("--index-runes").build();
+
+    let block_count = context.index.block_count().unwrap().into_usize();
+
+    context.mine_blocks(1);
+
+    let commit = context.core.broadcast_tx(TransactionTemplate {
+      inputs: &[(block_count, 0, 0, Witness::new())],
+      p2tr: true,
+      ..default()
+    });
+
+    context.mine_blocks(Runestone::COMMIT_CONFIRMATIONS.into());
+
+    let mut witness = Witness::new();
+
+    let runestone = Runestone {
+      etching: Some(Etching {
+        rune: Some(Rune(RUNE)),
+        terms: Some(Terms {
+          amount: Some(1000),
+          ..default()
+        }),
+        ..default()
+      }),
+      ..default()
+    };
+
+    let tapscript = script::Builder::new()
+      .push_slice::<&PushBytes>(
+        runestone
+          .etching
+          .unwrap()
+          .rune
+          .unwrap()
+          .commitment()
+          .as_slice()
+          .try_into()
+          .unwrap(),
+      )
+      .into_script();
+
+    witness.push(tapscript);
+
+    witness.push([]);
+
+    context.core.broadcast_tx(TransactionTemplate {
+      inputs: &[(block_count + 1, 1, 0, witness)],
+      op_return: Some(runestone.encipher()),
+      outputs: 1,
+      ..default()
+    });
+
+    context.mine_blocks_with_update(3, false);
+
+    {
+      let mut state = context.core.state();
+      let tip = u32::try_from(state.hashes.len() - 1).unwrap();
+      state.txid_to_block_height.insert(commit, tip);
+    }
+
+    context.index.update().unwrap();
+
+    context.assert_runes([], []);
+  }
+
   #[test]
   fn etchings_are_not_valid_without_commitment() {
     let context = Context::builder().arg("--index-runes").build();
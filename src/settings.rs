@@ -26,6 +26,7 @@ pub struct Settings {
   integration_test: bool,
   max_savepoints: Option<usize>,
   no_index_inscriptions: bool,
+  rune_event_log: Option<PathBuf>,
   savepoint_interval: Option<usize>,
   server_password: Option<String>,
   server_url: Option<String>,
@@ -141,6 +142,7 @@ impl Settings {
       integration_test: self.integration_test || source.integration_test,
       max_savepoints: self.max_savepoints.or(source.max_savepoints),
       no_index_inscriptions: self.no_index_inscriptions || source.no_index_inscriptions,
+      rune_event_log: self.rune_event_log.or(source.rune_event_log),
       savepoint_interval: self.savepoint_interval.or(source.savepoint_interval),
       server_password: self.server_password.or(source.server_password),
       server_url: self.server_url.or(source.server_url),
@@ -179,6 +181,7 @@ impl Settings {
       integration_test: options.integration_test,
       max_savepoints: options.max_savepoints,
       no_index_inscriptions: options.no_index_inscriptions,
+      rune_event_log: options.rune_event_log,
       savepoint_interval: options.savepoint_interval,
       server_password: options.server_password,
       server_url: None,
@@ -269,6 +272,7 @@ impl Settings {
       integration_test: get_bool("INTEGRATION_TEST"),
       max_savepoints: get_usize("MAX_SAVEPOINTS")?,
       no_index_inscriptions: get_bool("NO_INDEX_INSCRIPTIONS"),
+      rune_event_log: get_path("RUNE_EVENT_LOG"),
       savepoint_interval: get_usize("SAVEPOINT_INTERVAL")?,
       server_password: get_string("SERVER_PASSWORD"),
       server_url: get_string("SERVER_URL"),
@@ -301,6 +305,7 @@ impl Settings {
       integration_test: false,
       max_savepoints: None,
       no_index_inscriptions: false,
+      rune_event_log: None,
       savepoint_interval: None,
       server_password: None,
       server_url: Some(server_url.into()),
@@ -377,6 +382,7 @@ impl Settings {
       integration_test: self.integration_test,
       max_savepoints: Some(self.max_savepoints.unwrap_or(2)),
       no_index_inscriptions: self.no_index_inscriptions,
+      rune_event_log: self.rune_event_log,
       savepoint_interval: Some(self.savepoint_interval.unwrap_or(10)),
       server_password: self.server_password,
       server_url: self.server_url,
@@ -564,6 +570,10 @@ impl Settings {
     self.index_cache_size.unwrap()
   }
 
+  pub fn rune_event_log(&self) -> Option<&Path> {
+    self.rune_event_log.as_deref()
+  }
+
   pub fn index_sats_raw(&self) -> bool {
     self.index_sats
   }
@@ -1085,6 +1095,7 @@ mod tests {
       ("INTEGRATION_TEST", "1"),
       ("MAX_SAVEPOINTS", "2"),
       ("NO_INDEX_INSCRIPTIONS", "1"),
+      ("RUNE_EVENT_LOG", "rune event log"),
       ("SAVEPOINT_INTERVAL", "10"),
       ("SERVER_PASSWORD", "server password"),
       ("SERVER_URL", "server url"),
@@ -1132,6 +1143,7 @@ mod tests {
         index_transactions: true,
         integration_test: true,
         no_index_inscriptions: true,
+        rune_event_log: Some("rune event log".into()),
         server_password: Some("server password".into()),
         server_url: Some("server url".into()),
         server_username: Some("server username".into()),
@@ -1167,6 +1179,7 @@ mod tests {
           "--index=index",
           "--integration-test",
           "--no-index-inscriptions",
+          "--rune-event-log=rune event log",
           "--server-password=server password",
           "--server-username=server username",
         ])
@@ -1197,6 +1210,7 @@ mod tests {
         index_transactions: true,
         integration_test: true,
         no_index_inscriptions: true,
+        rune_event_log: Some("rune event log".into()),
         server_password: Some("server password".into()),
         server_url: None,
         server_username: Some("server username".into()),
@@ -84,7 +84,7 @@ impl Decode {
 
     let inscriptions = ParsedEnvelope::from_transaction(&transaction);
 
-    let runestone = Runestone::decipher(&transaction);
+    let runestone = Runestone::decipher(&transaction, Self::weighted_splits(&settings, self.txid)?);
 
     if self.compact {
       Ok(Some(Box::new(CompactOutput {
@@ -102,4 +102,29 @@ impl Decode {
       })))
     }
   }
+
+  // runestones are deciphered as of the transaction's confirmation height or,
+  // if it is unconfirmed or was not fetched from Bitcoin Core, the height of
+  // the next block
+  fn weighted_splits(settings: &Settings, txid: Option<Txid>) -> Result<bool> {
+    let chain = settings.chain();
+
+    if chain.weighted_split_height().is_none() {
+      return Ok(false);
+    }
+
+    let client = settings.bitcoin_rpc_client(None)?;
+
+    let blockhash = match txid {
+      Some(txid) => client.get_raw_transaction_info(&txid, None)?.blockhash,
+      None => None,
+    };
+
+    let height = match blockhash {
+      Some(blockhash) => u64::try_from(client.get_block_header_info(&blockhash)?.height)?,
+      None => client.get_block_count()? + 1,
+    };
+
+    Ok(chain.weighted_splits(height.try_into()?))
+  }
 }
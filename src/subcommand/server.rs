@@ -249,6 +249,7 @@ impl Server {
         .route("/preview/{inscription_id}", get(Self::preview))
         .route("/rare.txt", get(Self::rare_txt))
         .route("/rune/{rune}", get(Self::rune))
+        .route("/rune/{rune}/supply", get(Self::rune_supply))
         .route("/runes", get(Self::runes))
         .route("/runes/{page}", get(Self::runes_paginated))
         .route("/sat/{sat}", get(Self::sat))
@@ -261,6 +262,17 @@ impl Server {
         .route("/tx/{txid}", get(Self::transaction))
         .route("/update", get(Self::update));
 
+      // checking a commitment looks up caller-chosen transactions with
+      // bitcoind, so it is only served when the JSON API is enabled
+      let router = if server_config.json_api_enabled {
+        router.route(
+          "/rune/{rune}/commitment",
+          post(Self::rune_commitment).layer(body_limit),
+        )
+      } else {
+        router
+      };
+
       // recursive endpoints
       let router = router
         .route("/blockhash", get(r::blockhash_string))
@@ -993,6 +1005,78 @@ impl Server {
     })
   }
 
+  async fn rune_commitment(
+    Extension(index): Extension<Arc<Index>>,
+    Path(DeserializeFromStr(spaced_rune)): Path<DeserializeFromStr<SpacedRune>>,
+    AcceptJson(accept_json): AcceptJson,
+    Json(request): Json<api::CommitmentRequest>,
+  ) -> ServerResult {
+    if !accept_json {
+      return Ok(StatusCode::NOT_FOUND.into_response());
+    }
+
+    task::block_in_place(|| {
+      if !index.has_rune_index() {
+        return Err(ServerError::NotFound(
+          "this server has no rune index".to_string(),
+        ));
+      }
+
+      let reveal_tx = hex::decode(&request.transaction).map_err(|err| {
+        ServerError::BadRequest(format!("failed to hex decode transaction: {err}"))
+      })?;
+
+      let reveal_tx = consensus::deserialize::<Transaction>(&reveal_tx)
+        .map_err(|err| ServerError::BadRequest(format!("invalid transaction: {err}")))?;
+
+      Ok(Json(index.check_commitment(&reveal_tx, spaced_rune.rune)?).into_response())
+    })
+  }
+
+  async fn rune_supply(
+    Extension(index): Extension<Arc<Index>>,
+    Path(DeserializeFromStr(rune_query)): Path<DeserializeFromStr<query::Rune>>,
+    AcceptJson(accept_json): AcceptJson,
+  ) -> ServerResult {
+    if !accept_json {
+      return Ok(StatusCode::NOT_FOUND.into_response());
+    }
+
+    task::block_in_place(|| {
+      if !index.has_rune_index() {
+        return Err(ServerError::NotFound(
+          "this server has no rune index".to_string(),
+        ));
+      }
+
+      let rune = match rune_query {
+        query::Rune::Spaced(spaced_rune) => spaced_rune.rune,
+        query::Rune::Id(rune_id) => index
+          .get_rune_by_id(rune_id)?
+          .ok_or_not_found(|| format!("rune {rune_id}"))?,
+        query::Rune::Number(number) => index
+          .get_rune_by_number(usize::try_from(number).unwrap())?
+          .ok_or_not_found(|| format!("rune number {number}"))?,
+      };
+
+      let (_, entry, _) = index
+        .rune(rune)?
+        .ok_or_not_found(|| format!("rune {rune}"))?;
+
+      let supply = entry.supply();
+
+      Ok(
+        Json(api::RuneSupply {
+          burned: entry.burned,
+          circulating: supply.saturating_sub(entry.burned),
+          open_mint_supply: supply - entry.premine,
+          premine: entry.premine,
+        })
+        .into_response(),
+      )
+    })
+  }
+
   async fn runes(
     Extension(server_config): Extension<Arc<ServerConfig>>,
     Extension(index): Extension<Arc<Index>>,
@@ -1297,7 +1381,7 @@ impl Server {
         .ok_or_not_found(|| format!("transaction {txid}"))?;
 
       let inscriptions = ParsedEnvelope::from_transaction(&transaction);
-      let runestone = Runestone::decipher(&transaction);
+      let runestone = Runestone::decipher(&transaction, index.weighted_splits(txid)?);
 
       Ok(if accept_json {
         Json(api::Decode {
@@ -3274,6 +3358,189 @@ mod tests {
     );
   }
 
+  #[test]
+  fn rune_supply_json() {
+    let server = TestServer::builder()
+      .chain(Chain::Regtest)
+      .index_runes()
+      .build();
+
+    server.mine_blocks(1);
+
+    let rune = Rune(RUNE);
+
+    let (_txid, id) = server.etch(
+      Runestone {
+        edicts: vec![Edict {
+          id: RuneId::default(),
+          amount: 1000,
+          output: 0,
+        }],
+        etching: Some(Etching {
+          rune: Some(rune),
+          premine: Some(1000),
+          terms: Some(Terms {
+            amount: Some(100),
+            cap: Some(10),
+            ..default()
+          }),
+          ..default()
+        }),
+        ..default()
+      },
+      1,
+      None,
+    );
+
+    server.core.broadcast_tx(TransactionTemplate {
+      inputs: &[(1, 0, 0, Witness::new())],
+      op_return: Some(
+        Runestone {
+          mint: Some(id),
+          ..default()
+        }
+        .encipher(),
+      ),
+      ..default()
+    });
+
+    server.mine_blocks(1);
+
+    server.core.broadcast_tx(TransactionTemplate {
+      inputs: &[(id.block.try_into().unwrap(), 1, 0, Witness::new())],
+      op_return: Some(
+        Runestone {
+          edicts: vec![Edict {
+            id,
+            amount: 200,
+            output: 1,
+          }],
+          ..default()
+        }
+        .encipher(),
+      ),
+      ..default()
+    });
+
+    server.mine_blocks(1);
+
+    pretty_assert_eq!(
+      server.get_json::<api::RuneSupply>(format!("/rune/{rune}/supply")),
+      api::RuneSupply {
+        burned: 200,
+        circulating: 900,
+        open_mint_supply: 100,
+        premine: 1000,
+      }
+    );
+
+    pretty_assert_eq!(
+      server.get_json::<api::RuneSupply>(format!("/rune/{id}/supply")),
+      api::RuneSupply {
+        burned: 200,
+        circulating: 900,
+        open_mint_supply: 100,
+        premine: 1000,
+      }
+    );
+
+    server.assert_response(format!("/rune/{rune}/supply"), StatusCode::NOT_FOUND, "");
+  }
+
+  #[test]
+  fn rune_commitment_json() {
+    let server = TestServer::builder()
+      .chain(Chain::Regtest)
+      .index_runes()
+      .build();
+
+    server.mine_blocks(1);
+
+    let commit = server.core.broadcast_tx(TransactionTemplate {
+      inputs: &[(1, 0, 0, Witness::new())],
+      p2tr: true,
+      ..default()
+    });
+
+    server.mine_blocks((Runestone::COMMIT_CONFIRMATIONS - 1).into());
+
+    let mut witness = Witness::new();
+
+    witness.push(
+      script::Builder::new()
+        .push_slice::<&PushBytes>(Rune(RUNE).commitment().as_slice().try_into().unwrap())
+        .into_script(),
+    );
+
+    witness.push([]);
+
+    let request = serde_json::to_string(&api::CommitmentRequest {
+      transaction: hex::encode(consensus::serialize(&Transaction {
+        version: Version(2),
+        lock_time: LockTime::ZERO,
+        input: vec![TxIn {
+          previous_output: OutPoint {
+            txid: commit,
+            vout: 0,
+          },
+          script_sig: ScriptBuf::new(),
+          sequence: Sequence::ENABLE_RBF_NO_LOCKTIME,
+          witness,
+        }],
+        output: Vec::new(),
+      })),
+    })
+    .unwrap();
+
+    let response = reqwest::blocking::Client::new()
+      .post(server.join_url("rune/AAAAAAAAAAAAA/commitment"))
+      .header(header::ACCEPT, "application/json")
+      .header(header::CONTENT_TYPE, "application/json")
+      .body(request)
+      .send()
+      .unwrap();
+
+    assert_eq!(response.status(), StatusCode::OK);
+
+    pretty_assert_eq!(
+      response.json::<api::CommitmentStatus>().unwrap(),
+      api::CommitmentStatus {
+        commit_height: Some(2),
+        confirmations: Some(6),
+        input: Some(OutPoint {
+          txid: commit,
+          vout: 0,
+        }),
+        required_confirmations: Runestone::COMMIT_CONFIRMATIONS.into(),
+        taproot: true,
+        valid: true,
+      }
+    );
+  }
+
+  #[test]
+  fn rune_commitment_is_not_served_when_json_api_is_disabled() {
+    let server = TestServer::builder()
+      .chain(Chain::Regtest)
+      .index_runes()
+      .server_flag("--disable-json-api")
+      .build();
+
+    let response = reqwest::blocking::Client::new()
+      .post(server.join_url("rune/AAAAAAAAAAAAA/commitment"))
+      .header(header::CONTENT_TYPE, "application/json")
+      .body(
+        serde_json::to_string(&api::CommitmentRequest {
+          transaction: String::new(),
+        })
+        .unwrap(),
+      )
+      .send()
+      .unwrap();
+
+    assert_eq!(response.status(), StatusCode::NOT_FOUND);
+  }
+
   #[test]
   fn etched_runes_are_displayed_on_block_page() {
     let server = TestServer::builder()
@@ -100,7 +100,7 @@ impl Mint {
     let signed_transaction = consensus::encode::deserialize(&signed_transaction)?;
 
     assert_eq!(
-      Runestone::decipher(&signed_transaction),
+      Runestone::decipher(&signed_transaction, false),
       Some(Artifact::Runestone(runestone)),
     );
 
@@ -1129,7 +1129,7 @@ impl Wallet {
 
     if needs_runes_change_output {
       assert_eq!(
-        Runestone::decipher(&unsigned_transaction),
+        Runestone::decipher(&unsigned_transaction, false),
         Some(Artifact::Runestone(runestone)),
       );
     }
@@ -477,6 +477,7 @@ impl Plan {
         }),
         mint: None,
         pointer: (premine > 0).then_some((reveal_outputs.len() - 1).try_into().unwrap()),
+        weighted: false,
       };
 
       let script_pubkey = inner.encipher();
@@ -641,7 +642,7 @@ impl Plan {
     let total_fees =
       Self::calculate_fee(&unsigned_commit_tx, &utxos) + Self::calculate_fee(&reveal_tx, &utxos);
 
-    match (Runestone::decipher(&reveal_tx), runestone) {
+    match (Runestone::decipher(&reveal_tx, false), runestone) {
       (Some(actual), Some(expected)) => assert_eq!(
         actual,
         Artifact::Runestone(expected),
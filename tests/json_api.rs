@@ -749,7 +749,7 @@ fn get_decode_tx() {
     pushnum: false,
     stutter: false,
   }];
-  let runestone = Runestone::decipher(&transaction);
+  let runestone = Runestone::decipher(&transaction, false);
   let response = ord.json_request(format!("/decode/{txid}"));
 
   assert_eq!(response.status(), StatusCode::OK);
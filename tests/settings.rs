@@ -29,6 +29,7 @@ fn default() {
   "integration_test": false,
   "max_savepoints": 2,
   "no_index_inscriptions": false,
+  "rune_event_log": null,
   "savepoint_interval": 10,
   "server_password": null,
   "server_url": null,
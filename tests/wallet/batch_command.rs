@@ -1610,7 +1610,7 @@ fn batch_can_etch_rune() {
     Sequence::from_height(Runestone::COMMIT_CONFIRMATIONS - 1)
   );
 
-  let Artifact::Runestone(runestone) = Runestone::decipher(&reveal).unwrap() else {
+  let Artifact::Runestone(runestone) = Runestone::decipher(&reveal, false).unwrap() else {
     panic!();
   };
 
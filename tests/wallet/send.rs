@@ -1271,7 +1271,7 @@ fn sending_rune_creates_transaction_with_expected_runestone() {
   let tx = core.tx_by_id(output.txid);
 
   pretty_assert_eq!(
-    Runestone::decipher(&tx).unwrap(),
+    Runestone::decipher(&tx, false).unwrap(),
     Artifact::Runestone(Runestone {
       pointer: None,
       etching: None,
@@ -1281,6 +1281,7 @@ fn sending_rune_creates_transaction_with_expected_runestone() {
         output: 2
       }],
       mint: None,
+      weighted: false,
     }),
   );
 }